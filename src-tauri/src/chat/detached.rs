@@ -5,28 +5,134 @@
 //! which Jean tails for real-time updates.
 
 use std::path::Path;
-use std::process::Stdio;
+use std::process::{Command, Stdio};
+use std::time::Duration;
 
 #[cfg(unix)]
-use std::io::{BufRead, BufReader};
+use std::os::unix::process::CommandExt;
 
 // Re-export is_process_alive from platform module
 pub use crate::platform::is_process_alive;
+#[cfg(windows)]
+use crate::platform::shell::quote;
 use crate::platform::silent_command;
 
-/// Escape a string for safe use in a shell command.
-#[cfg(unix)]
-fn shell_escape(s: &str) -> String {
-    // Use single quotes and escape any single quotes within
-    format!("'{}'", s.replace('\'', "'\\''"))
+/// Exit status of a detached process, reconstructed from its status sidecar
+/// file rather than from a direct `wait()` (Jean can no longer `wait()` on a
+/// process once it's fully detached).
+///
+/// On Unix, the sidecar is written by the `sh` wrapper `spawn_detached_claude`
+/// runs Claude under (see `STATUS_WRAPPER_SCRIPT`), which tells `Exited` from
+/// `Signaled` using the POSIX `$? >= 128` convention rather than a real
+/// `wait()` status — so a process that calls `exit(137)` directly is
+/// indistinguishable from one killed by `SIGKILL` (9) and gets misreported
+/// as `Signaled(9)`. This is a heuristic, not a guarantee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetachedExitStatus {
+    /// No parsable status file yet. Liveness is not checked: a wrapper that
+    /// was killed before writing one (e.g. by `terminate_detached`) also
+    /// reads as `Running`.
+    Running,
+    /// Exited normally with the given code.
+    Exited(i32),
+    /// Killed by the given signal (Unix only; never produced on Windows).
+    /// See the caveat on this enum about `exit(128 + n)` being ambiguous
+    /// with an actual signal `n`.
+    Signaled(i32),
+}
+
+/// Path to the sidecar file a detached process's exit status is recorded
+/// to by the wrapper script it runs under — see `STATUS_WRAPPER_SCRIPT`
+/// (Unix) and `status_wrapper_command_line` (Windows).
+fn status_file_path(output_file: &Path) -> std::path::PathBuf {
+    let mut name = output_file.as_os_str().to_os_string();
+    name.push(".status");
+    std::path::PathBuf::from(name)
+}
+
+/// Read back the exit status the wrapper script recorded for `output_file`'s
+/// process, or `Running` if it hasn't written one (yet).
+pub fn poll_exit_status(output_file: &Path) -> DetachedExitStatus {
+    let Ok(contents) = std::fs::read_to_string(status_file_path(output_file)) else {
+        return DetachedExitStatus::Running;
+    };
+    parse_status_line(contents.trim()).unwrap_or(DetachedExitStatus::Running)
+}
+
+fn parse_status_line(line: &str) -> Option<DetachedExitStatus> {
+    let (kind, code) = line.split_once('=')?;
+    let code: i32 = code.parse().ok()?;
+    match kind {
+        "exit" => Some(DetachedExitStatus::Exited(code)),
+        "signal" => Some(DetachedExitStatus::Signaled(code)),
+        _ => None,
+    }
+}
+
+/// A detached process plus the id of the process group it leads.
+///
+/// Both platforms put the process into its own group at spawn time (Unix via
+/// `setsid()`, Windows via `CREATE_NEW_PROCESS_GROUP`), so `pgid` is always
+/// equal to `pid` today — but callers should go through `pgid` rather than
+/// assuming that, since `terminate_detached` targets the whole group so MCP
+/// subprocesses Claude forked get signalled too.
+///
+/// `cat_pid` is set on Unix when `spawn_detached_claude` fed Claude's stdin
+/// through a separate `cat` process. `cat` calls its own `setsid()`, so it
+/// ends up leading a *different* group from Claude's — `pgid` alone can't
+/// reach it — and `terminate_detached` signals this pid/group explicitly in
+/// addition to `pgid`. `None` on Windows and for the streaming spawn variant,
+/// neither of which spawn a `cat` helper.
+#[derive(Debug, Clone, Copy)]
+pub struct DetachedProcess {
+    pub pid: u32,
+    pub pgid: u32,
+    pub cat_pid: Option<u32>,
 }
 
+/// `sh -c` script body that runs a command and writes its own exit status
+/// to a sidecar file, both passed in as ordinary positional parameters
+/// rather than interpolated into the script text.
+///
+/// Spawned as `sh -c STATUS_WRAPPER_SCRIPT sh <status_path> <cli_path>
+/// <args...>`: `$1` is the sidecar path, and after `shift`, `"$@"` is the
+/// command to run. Passing these as `Command::arg`s means no
+/// quoting/escaping step is needed at all — unlike building a command-line
+/// string, this can't be broken by shell metacharacters in an argument, and
+/// it doesn't force paths or arguments through a lossy UTF-8 conversion
+/// first (`Command::arg` takes anything `impl AsRef<OsStr>`, so arbitrary
+/// non-UTF-8 Unix paths pass through untouched).
+///
+/// This script, not Claude itself, is what `spawn_detached_claude` actually
+/// execs as the detached process. Only a process's real parent can `wait()`
+/// it and learn its exit status, and Jean stops being that parent the
+/// moment it quits — an in-process reaper thread dies right along with it.
+/// A shell child, on the other hand, gets reparented (to init, typically)
+/// and keeps running — and keeps writing — for as long as Claude does,
+/// entirely independent of whether Jean is still alive to watch it.
+#[cfg(unix)]
+const STATUS_WRAPPER_SCRIPT: &str = "status_path=$1\n\
+     shift\n\
+     \"$@\"\n\
+     rc=$?\n\
+     if [ \"$rc\" -ge 128 ]; then printf 'signal=%d\\n' \"$((rc - 128))\"; \
+     else printf 'exit=%d\\n' \"$rc\"; fi > \"$status_path\"\n";
+
 /// Spawn Claude CLI as a detached process that survives Jean quitting (Unix).
 ///
-/// Uses `nohup` and shell backgrounding to fully detach the process.
-/// The process reads input from a file and writes output to the NDJSON file.
+/// Builds a two-process pipeline directly with `std::process::Command` —
+/// `cat` reads the input file and feeds Claude's stdin — but Claude itself
+/// is run through the `sh -c` wrapper from `STATUS_WRAPPER_SCRIPT` rather
+/// than execed directly, so the wrapper can record Claude's real exit
+/// status after Jean is gone. Claude's stdout and stderr both go to the
+/// (already-created) output file. Each child calls `setsid()` before exec
+/// so it leaves Jean's session and survives SIGHUP, the same immunity
+/// `nohup` used to provide, and becomes the leader of its own process group.
 ///
-/// Returns the PID of the detached Claude CLI process.
+/// Returns the wrapper script's PID and process group id — not Claude's own
+/// PID, which Jean never sees directly. `terminate_detached` still reaches
+/// Claude through it: the wrapper doesn't call `setsid()` again around the
+/// inner command, so Claude inherits the wrapper's process group.
 #[cfg(unix)]
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_detached_claude(
@@ -36,135 +142,289 @@ pub fn spawn_detached_claude(
     output_file: &Path,
     working_dir: &Path,
     env_vars: &[(&str, &str)],
-) -> Result<u32, String> {
-    // Build the shell command:
-    // cat input.jsonl | nohup /path/to/claude [args] >> output.jsonl 2>&1 & echo $!
-    //
-    // NOTE: We use `cat file | nohup claude` instead of `nohup claude < file` because
-    // Claude CLI with --print doesn't accept stdin from file redirection, only from pipes.
-    //
-    // - cat: Reads input file and pipes to stdin
-    // - nohup: Makes the process immune to SIGHUP (sent when terminal closes)
-    // - >> output.jsonl: Appends output to file (Claude writes here)
-    // - 2>&1: Redirect stderr to stdout (both go to output file)
-    // - &: Run in background
-    // - echo $!: Print the PID of the background process
-
-    // Escape ALL paths for safe shell usage (paths may contain spaces like "Application Support")
-    let cli_path_escaped =
-        shell_escape(cli_path.to_str().ok_or("CLI path contains invalid UTF-8")?);
-    let input_path_escaped = shell_escape(
-        input_file
-            .to_str()
-            .ok_or("Input file path contains invalid UTF-8")?,
-    );
-    let output_path_escaped = shell_escape(
-        output_file
-            .to_str()
-            .ok_or("Output file path contains invalid UTF-8")?,
-    );
-
-    // Build args string with proper escaping
-    let args_str = args
-        .iter()
-        .map(|arg| shell_escape(arg))
-        .collect::<Vec<_>>()
-        .join(" ");
-
-    // Build environment variable exports
-    let env_exports = env_vars
-        .iter()
-        .map(|(k, v)| format!("{}={}", k, shell_escape(v)))
-        .collect::<Vec<_>>()
-        .join(" ");
-
-    // The full shell command - use cat pipe instead of file redirection
-    // Claude CLI with --print requires piped stdin, not file redirection
-    // NOTE: env vars must be placed AFTER the pipe so they apply to Claude, not cat
-    let shell_cmd = if env_exports.is_empty() {
-        format!(
-            "cat {input_path_escaped} | nohup {cli_path_escaped} {args_str} >> {output_path_escaped} 2>&1 & echo $!"
-        )
-    } else {
-        format!(
-            "cat {input_path_escaped} | {env_exports} nohup {cli_path_escaped} {args_str} >> {output_path_escaped} 2>&1 & echo $!"
-        )
-    };
+) -> Result<DetachedProcess, String> {
+    use std::fs::OpenOptions;
+
+    // `cat` feeds the input file into Claude's stdin. We use a pipe rather
+    // than file redirection because Claude CLI with --print only accepts
+    // piped stdin, not a redirected file.
+    let mut cat_cmd = silent_command("cat");
+    cat_cmd
+        .arg(input_file)
+        .current_dir(working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+    detach_pre_exec(&mut cat_cmd);
+
+    let mut cat_child = cat_cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn cat: {e}"))?;
+    let cat_stdout = cat_child
+        .stdout
+        .take()
+        .ok_or("Failed to capture cat stdout")?;
+
+    let out_file = OpenOptions::new()
+        .append(true)
+        .open(output_file)
+        .map_err(|e| format!("Failed to open output file: {e}"))?;
+    let err_file = out_file
+        .try_clone()
+        .map_err(|e| format!("Failed to clone output file handle: {e}"))?;
+
+    let mut claude_cmd = silent_command("sh");
+    claude_cmd
+        .arg("-c")
+        .arg(STATUS_WRAPPER_SCRIPT)
+        .arg("sh") // becomes $0 inside the script; conventionally the program name
+        .arg(status_file_path(output_file))
+        .arg(cli_path)
+        .args(args)
+        .current_dir(working_dir)
+        .stdin(Stdio::from(cat_stdout))
+        .stdout(out_file)
+        .stderr(err_file);
+    for (key, value) in env_vars {
+        claude_cmd.env(key, value);
+    }
+    detach_pre_exec(&mut claude_cmd);
 
     log::trace!("Spawning detached Claude CLI");
-    log::trace!("Shell command: {shell_cmd}");
+    log::trace!("CLI path: {cli_path:?}");
     log::trace!("Working directory: {working_dir:?}");
 
-    // Spawn the shell command
-    let mut child = silent_command("sh")
+    let mut claude_child = claude_cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn Claude CLI: {e}"))?;
+    let pid = claude_child.id();
+    let cat_pid = cat_child.id();
+
+    // Neither child is meant to be waited on here — both are detached and
+    // expected to keep running after Jean exits. The wrapper script records
+    // its own exit status directly to the sidecar (see
+    // `STATUS_WRAPPER_SCRIPT`), so these threads exist purely to reap the
+    // children and avoid zombies if they exit while Jean is still running.
+    std::thread::spawn(move || {
+        let _ = cat_child.wait();
+    });
+    std::thread::spawn(move || {
+        let _ = claude_child.wait();
+    });
+
+    log::trace!("Detached Claude CLI spawned with PID: {pid}");
+
+    // setsid() makes the child both its own session leader and its own
+    // process group leader, so the group id is the same as its pid. `cat`
+    // called its own setsid() too, so it leads a separate group that this
+    // pgid can't reach — terminate_detached signals cat_pid explicitly.
+    Ok(DetachedProcess {
+        pid,
+        pgid: pid,
+        cat_pid: Some(cat_pid),
+    })
+}
+
+/// Like `spawn_detached_claude`, but streams `input` to Claude's stdin on a
+/// dedicated writer thread instead of first materializing it into a file and
+/// piping it through `cat`. Lets Jean feed a conversation turn-by-turn, or
+/// pipe generated content straight through, without a temp file. Detachment,
+/// process-group handling and exit-status recording (via the same
+/// `STATUS_WRAPPER_SCRIPT` wrapper `spawn_detached_claude` uses) are
+/// otherwise identical.
+#[cfg(unix)]
+pub fn spawn_detached_claude_streaming<R>(
+    cli_path: &Path,
+    args: &[String],
+    mut input: R,
+    output_file: &Path,
+    working_dir: &Path,
+    env_vars: &[(&str, &str)],
+) -> Result<DetachedProcess, String>
+where
+    R: std::io::Read + Send + 'static,
+{
+    use std::fs::OpenOptions;
+
+    let out_file = OpenOptions::new()
+        .append(true)
+        .open(output_file)
+        .map_err(|e| format!("Failed to open output file: {e}"))?;
+    let err_file = out_file
+        .try_clone()
+        .map_err(|e| format!("Failed to clone output file handle: {e}"))?;
+
+    let mut claude_cmd = silent_command("sh");
+    claude_cmd
         .arg("-c")
-        .arg(&shell_cmd)
+        .arg(STATUS_WRAPPER_SCRIPT)
+        .arg("sh") // becomes $0 inside the script; conventionally the program name
+        .arg(status_file_path(output_file))
+        .arg(cli_path)
+        .args(args)
         .current_dir(working_dir)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
+        .stdin(Stdio::piped())
+        .stdout(out_file)
+        .stderr(err_file);
+    for (key, value) in env_vars {
+        claude_cmd.env(key, value);
+    }
+    detach_pre_exec(&mut claude_cmd);
+
+    log::trace!("Spawning detached Claude CLI with streaming stdin");
+    log::trace!("CLI path: {cli_path:?}");
+    log::trace!("Working directory: {working_dir:?}");
+
+    let mut claude_child = claude_cmd
         .spawn()
-        .map_err(|e| format!("Failed to spawn shell: {e}"))?;
+        .map_err(|e| format!("Failed to spawn Claude CLI: {e}"))?;
+    let pid = claude_child.id();
 
-    // Read the PID from stdout (the `echo $!` part)
-    let stdout = child
-        .stdout
+    let mut stdin = claude_child
+        .stdin
         .take()
-        .ok_or("Failed to capture shell stdout")?;
-    let reader = BufReader::new(stdout);
-
-    let mut pid_str = String::new();
-    for line in reader.lines() {
-        match line {
-            Ok(l) => {
-                pid_str = l.trim().to_string();
-                break;
-            }
-            Err(e) => {
-                log::warn!("Error reading PID from shell: {e}");
-            }
+        .ok_or("Failed to capture Claude CLI stdin")?;
+    std::thread::spawn(move || {
+        if let Err(e) = std::io::copy(&mut input, &mut stdin) {
+            log::warn!("Failed to stream input to Claude CLI: {e}");
         }
+        // `stdin` is dropped here, closing the pipe and signalling EOF.
+    });
+
+    // The wrapper script records its own exit status directly to the
+    // sidecar (see `STATUS_WRAPPER_SCRIPT`), so this thread exists purely
+    // to reap the child and avoid it lingering as a zombie if it exits
+    // while Jean is still running.
+    std::thread::spawn(move || {
+        let _ = claude_child.wait();
+    });
+
+    log::trace!("Detached Claude CLI spawned with PID: {pid}");
+
+    Ok(DetachedProcess {
+        pid,
+        pgid: pid,
+        cat_pid: None,
+    })
+}
+
+/// Put `cmd`'s child into its own session (and therefore its own process
+/// group) right before exec, so it survives Jean's session ending.
+#[cfg(unix)]
+fn detach_pre_exec(cmd: &mut Command) {
+    unsafe {
+        cmd.pre_exec(|| {
+            libc::setsid();
+            Ok(())
+        });
     }
+}
 
-    // Capture stderr for error reporting
-    let stderr_handle = child.stderr.take();
-
-    // Wait for shell to finish (it returns immediately after backgrounding)
-    let status = child
-        .wait()
-        .map_err(|e| format!("Failed to wait for shell: {e}"))?;
-
-    if !status.success() {
-        // Read stderr to provide better error messages
-        let stderr_output = stderr_handle
-            .map(|stderr| {
-                BufReader::new(stderr)
-                    .lines()
-                    .map_while(Result::ok)
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            })
-            .unwrap_or_default();
+/// Send `SIGTERM` to the whole process group, wait up to `grace` for it to
+/// exit, and escalate to `SIGKILL` if it's still alive afterwards.
+///
+/// Signalling the group (rather than just `process.pid`) reaches any MCP
+/// subprocesses Claude forked, not just the Claude process itself.
+#[cfg(unix)]
+pub fn terminate_detached(process: &DetachedProcess, grace: Duration) -> Result<(), String> {
+    send_signal_to_group(process.pgid, libc::SIGTERM)?;
+    // `cat` leads its own group (see `DetachedProcess::cat_pid`), so `pgid`
+    // above doesn't reach it — signal it separately.
+    if let Some(cat_pid) = process.cat_pid {
+        send_signal_to_group(cat_pid, libc::SIGTERM)?;
+    }
 
-        return Err(format!(
-            "Shell command failed with status: {status}\nStderr: {stderr_output}"
-        ));
+    let still_alive = |process: &DetachedProcess| {
+        is_process_alive(process.pid) || process.cat_pid.is_some_and(is_process_alive)
+    };
+
+    let deadline = std::time::Instant::now() + grace;
+    while std::time::Instant::now() < deadline {
+        if !still_alive(process) {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(100));
     }
 
-    // Parse the PID
-    let pid: u32 = pid_str
-        .parse()
-        .map_err(|e| format!("Failed to parse PID '{pid_str}': {e}"))?;
+    if is_process_alive(process.pid) {
+        send_signal_to_group(process.pgid, libc::SIGKILL)?;
+    }
+    if let Some(cat_pid) = process.cat_pid {
+        if is_process_alive(cat_pid) {
+            send_signal_to_group(cat_pid, libc::SIGKILL)?;
+        }
+    }
 
-    log::trace!("Detached Claude CLI spawned with PID: {pid}");
+    Ok(())
+}
+
+#[cfg(unix)]
+fn send_signal_to_group(pgid: u32, signal: i32) -> Result<(), String> {
+    // A negative pid targets the whole process group rather than a single process.
+    let result = unsafe { libc::kill(-(pgid as i32), signal) };
+    if result != 0 {
+        let err = std::io::Error::last_os_error();
+        // ESRCH just means the group is already gone, which is the outcome we wanted.
+        if err.raw_os_error() == Some(libc::ESRCH) {
+            return Ok(());
+        }
+        return Err(format!(
+            "Failed to send signal {signal} to process group {pgid}: {err}"
+        ));
+    }
+    Ok(())
+}
 
-    Ok(pid)
+/// Build the `cmd /C` command line that runs `cli_path args...` and then
+/// writes its own exit code to `output_file`'s status sidecar.
+///
+/// Mirrors `STATUS_WRAPPER_SCRIPT` on Unix: this wrapper, not Claude
+/// itself, is the process `spawn_detached_claude` actually creates, so exit
+/// status recording doesn't depend on Jean still being alive to `wait()` on
+/// anything. `/V:ON` turns on delayed expansion so `!errorlevel!` is
+/// expanded when the `echo` runs, after the CLI has exited, rather than
+/// being pre-expanded (to the wrapper's own stale value) when the line is
+/// first parsed.
+///
+/// Unlike the Unix wrapper, this can't avoid building a command-line
+/// string: `cmd /C` always takes the entire rest of the line as a single
+/// argument, there's no positional-parameter escape hatch. So paths and
+/// args are required to be valid UTF-8 here (returning an error if not)
+/// rather than silently mangling them through a lossy conversion — the
+/// caller already requires the *result* to round-trip through `quote()`
+/// correctly, and a lossy `to_string_lossy()` would corrupt that silently.
+#[cfg(windows)]
+fn status_wrapper_command_line(
+    cli_path: &Path,
+    args: &[String],
+    output_file: &Path,
+) -> Result<String, String> {
+    let status_path = status_file_path(output_file);
+    let cli_str = cli_path.to_str().ok_or("CLI path contains invalid UTF-8")?;
+    let status_str = status_path
+        .to_str()
+        .ok_or("Status sidecar path contains invalid UTF-8")?;
+
+    let mut command_line = quote(cli_str);
+    for arg in args {
+        command_line.push(' ');
+        command_line.push_str(&quote(arg));
+    }
+    command_line.push_str(" & echo exit=!errorlevel!> ");
+    command_line.push_str(&quote(status_str));
+    Ok(command_line)
 }
 
 /// Spawn Claude CLI as a detached native Windows process.
 ///
-/// Runs claude.exe directly with stdout/stderr redirected to the output file.
-/// Returns the Windows PID of the Claude CLI process.
+/// Runs claude.exe through the `cmd /V:ON /C` wrapper from
+/// `status_wrapper_command_line` rather than directly, so the wrapper can
+/// record Claude's real exit code after Jean is gone, with stdout/stderr
+/// redirected to the output file. `CREATE_NEW_PROCESS_GROUP` makes the
+/// wrapper the root of its own group, so its Windows PID doubles as the
+/// group id `GenerateConsoleCtrlEvent` needs — Claude, spawned by the
+/// wrapper within that same command line, inherits the group.
+/// Returns the wrapper's Windows PID, not Claude's own.
 #[cfg(windows)]
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_detached_claude(
@@ -174,7 +434,7 @@ pub fn spawn_detached_claude(
     output_file: &Path,
     working_dir: &Path,
     env_vars: &[(&str, &str)],
-) -> Result<u32, String> {
+) -> Result<DetachedProcess, String> {
     use std::fs::OpenOptions;
     use std::io::Write;
     use std::os::windows::process::CommandExt;
@@ -193,11 +453,21 @@ pub fn spawn_detached_claude(
         .try_clone()
         .map_err(|e| format!("Failed to clone output file handle: {e}"))?;
 
-    // Build command - run claude.exe directly
+    // Build command - run claude.exe through the status-recording wrapper.
     // NOTE: silent_command sets CREATE_NO_WINDOW, but creation_flags() replaces
     // (doesn't merge), so we must re-specify both flags here.
-    let mut cmd = silent_command(cli_path);
-    cmd.args(args)
+    //
+    // `command_line` is already a complete, valid command line on its own
+    // (each token individually quoted by `quote()`) — passed as a single
+    // `raw_arg` with no further wrapping, exactly as if typed directly
+    // after `cmd /C` at a prompt. Wrapping it in another pair of quotes
+    // would produce a doubled leading/trailing quote that cmd.exe's /C
+    // quote-stripping heuristic can mis-tokenize.
+    let command_line = status_wrapper_command_line(cli_path, args, output_file)?;
+    let mut cmd = silent_command("cmd");
+    cmd.raw_arg("/V:ON")
+        .raw_arg("/C")
+        .raw_arg(&command_line)
         .current_dir(working_dir)
         .stdin(Stdio::piped())
         .stdout(out_file)
@@ -233,22 +503,179 @@ pub fn spawn_detached_claude(
 
     log::trace!("Detached Claude CLI spawned with Windows PID: {pid}");
 
-    Ok(pid)
+    // The wrapper command line records its own exit status directly to the
+    // sidecar (see `status_wrapper_command_line`), so Jean only needs to
+    // reap this child to avoid it lingering if it exits while Jean is still
+    // running.
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    Ok(DetachedProcess {
+        pid,
+        pgid: pid,
+        cat_pid: None,
+    })
+}
+
+/// Like `spawn_detached_claude`, but streams `input` to Claude's stdin on a
+/// dedicated writer thread instead of reading the whole input file into
+/// memory before the first `write_all`. Lets Jean feed a conversation
+/// turn-by-turn, or pipe generated content straight through. Exit-status
+/// recording goes through the same `status_wrapper_command_line` wrapper
+/// `spawn_detached_claude` uses.
+#[cfg(windows)]
+pub fn spawn_detached_claude_streaming<R>(
+    cli_path: &Path,
+    args: &[String],
+    mut input: R,
+    output_file: &Path,
+    working_dir: &Path,
+    env_vars: &[(&str, &str)],
+) -> Result<DetachedProcess, String>
+where
+    R: std::io::Read + Send + 'static,
+{
+    use std::fs::OpenOptions;
+    use std::os::windows::process::CommandExt;
+
+    const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+    const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+    let out_file = OpenOptions::new()
+        .append(true)
+        .open(output_file)
+        .map_err(|e| format!("Failed to open output file: {e}"))?;
+    let err_file = out_file
+        .try_clone()
+        .map_err(|e| format!("Failed to clone output file handle: {e}"))?;
+
+    let command_line = status_wrapper_command_line(cli_path, args, output_file)?;
+    let mut cmd = silent_command("cmd");
+    cmd.raw_arg("/V:ON")
+        .raw_arg("/C")
+        .raw_arg(&command_line)
+        .current_dir(working_dir)
+        .stdin(Stdio::piped())
+        .stdout(out_file)
+        .stderr(err_file)
+        .creation_flags(CREATE_NEW_PROCESS_GROUP | CREATE_NO_WINDOW);
+    for (key, value) in env_vars {
+        cmd.env(key, value);
+    }
+
+    log::trace!("Spawning detached Claude CLI natively on Windows (streaming stdin)");
+    log::trace!("CLI path: {cli_path:?}");
+    log::trace!("Working directory: {working_dir:?}");
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn Claude CLI: {e}"))?;
+    let pid = child.id();
+
+    let mut stdin = child
+        .stdin
+        .take()
+        .ok_or("Failed to capture Claude CLI stdin")?;
+    std::thread::spawn(move || {
+        if let Err(e) = std::io::copy(&mut input, &mut stdin) {
+            log::warn!("Failed to stream input to Claude CLI: {e}");
+        }
+        // `stdin` is dropped here, closing the pipe and signalling EOF.
+    });
+
+    log::trace!("Detached Claude CLI spawned with Windows PID: {pid}");
+
+    // The wrapper command line records its own exit status directly to the
+    // sidecar (see `status_wrapper_command_line`), so this thread exists
+    // purely to reap the child and avoid it lingering if it exits while
+    // Jean is still running.
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+
+    Ok(DetachedProcess {
+        pid,
+        pgid: pid,
+        cat_pid: None,
+    })
+}
+
+/// Send `CTRL_BREAK_EVENT` to the whole process group, wait up to `grace`
+/// for it to exit, and escalate to `TerminateProcess` if it's still alive.
+#[cfg(windows)]
+pub fn terminate_detached(process: &DetachedProcess, grace: Duration) -> Result<(), String> {
+    windows_ffi::generate_ctrl_break(process.pgid)?;
+
+    let deadline = std::time::Instant::now() + grace;
+    while std::time::Instant::now() < deadline {
+        if !is_process_alive(process.pid) {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    if is_process_alive(process.pid) {
+        windows_ffi::terminate_process(process.pid)?;
+    }
+
+    Ok(())
+}
+
+/// Minimal Win32 bindings for group-wide termination. We declare these
+/// directly (matching the raw `CREATE_NEW_PROCESS_GROUP`/`CREATE_NO_WINDOW`
+/// constants above) rather than pulling in a crate for two functions.
+#[cfg(windows)]
+mod windows_ffi {
+    use std::ffi::c_void;
+
+    const CTRL_BREAK_EVENT: u32 = 1;
+    const PROCESS_TERMINATE: u32 = 0x0001;
+
+    extern "system" {
+        fn GenerateConsoleCtrlEvent(dw_ctrl_event: u32, dw_process_group_id: u32) -> i32;
+        fn OpenProcess(
+            dw_desired_access: u32,
+            b_inherit_handle: i32,
+            dw_process_id: u32,
+        ) -> *mut c_void;
+        fn TerminateProcess(h_process: *mut c_void, u_exit_code: u32) -> i32;
+        fn CloseHandle(h_object: *mut c_void) -> i32;
+    }
+
+    pub(super) fn generate_ctrl_break(pgid: u32) -> Result<(), String> {
+        let ok = unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pgid) };
+        if ok == 0 {
+            return Err(format!(
+                "Failed to send CTRL_BREAK_EVENT to process group {pgid}: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+        Ok(())
+    }
+
+    pub(super) fn terminate_process(pid: u32) -> Result<(), String> {
+        let handle = unsafe { OpenProcess(PROCESS_TERMINATE, 0, pid) };
+        if handle.is_null() {
+            // Already gone is the outcome we wanted.
+            return Ok(());
+        }
+        let result = unsafe { TerminateProcess(handle, 1) };
+        unsafe { CloseHandle(handle) };
+        if result == 0 {
+            return Err(format!(
+                "Failed to terminate process {pid}: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    #[cfg(unix)]
-    fn test_shell_escape() {
-        assert_eq!(shell_escape("hello"), "'hello'");
-        assert_eq!(shell_escape("hello world"), "'hello world'");
-        assert_eq!(shell_escape("it's"), "'it'\\''s'");
-        assert_eq!(shell_escape(""), "''");
-    }
-
     #[test]
     fn test_is_process_alive() {
         // Current process should be alive
@@ -258,4 +685,207 @@ mod tests {
         // Non-existent PID should not be alive
         assert!(!is_process_alive(999999));
     }
+
+    #[test]
+    fn test_parse_status_line() {
+        assert_eq!(
+            parse_status_line("exit=0"),
+            Some(DetachedExitStatus::Exited(0))
+        );
+        assert_eq!(
+            parse_status_line("exit=137"),
+            Some(DetachedExitStatus::Exited(137))
+        );
+        assert_eq!(
+            parse_status_line("signal=9"),
+            Some(DetachedExitStatus::Signaled(9))
+        );
+        assert_eq!(parse_status_line("garbage"), None);
+        assert_eq!(parse_status_line("exit=not-a-number"), None);
+        assert_eq!(parse_status_line("bogus=0"), None);
+        assert_eq!(parse_status_line(""), None);
+    }
+
+    #[test]
+    fn test_poll_exit_status_running_when_no_sidecar() {
+        let dir = std::env::temp_dir().join(format!(
+            "jean-detached-test-{}-{}",
+            std::process::id(),
+            "poll-running"
+        ));
+        let output_file = dir.join("output.jsonl");
+        // No sidecar file has been written for this path, so this must
+        // report `Running` rather than erroring.
+        assert_eq!(poll_exit_status(&output_file), DetachedExitStatus::Running);
+    }
+
+    #[test]
+    fn test_poll_exit_status_reads_sidecar() {
+        let dir = std::env::temp_dir().join(format!(
+            "jean-detached-test-{}-{}",
+            std::process::id(),
+            "poll-sidecar"
+        ));
+        std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let output_file = dir.join("output.jsonl");
+        std::fs::write(status_file_path(&output_file), "exit=42\n")
+            .expect("failed to write status sidecar");
+
+        assert_eq!(
+            poll_exit_status(&output_file),
+            DetachedExitStatus::Exited(42)
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_status_wrapper_script_runs_command_and_records_exit() {
+        let dir = std::env::temp_dir().join(format!(
+            "jean-detached-test-{}-{}",
+            std::process::id(),
+            "wrapper-script"
+        ));
+        std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let output_file = dir.join("output.jsonl");
+        let status_path = status_file_path(&output_file);
+
+        // Positional params, exactly as `spawn_detached_claude` passes them:
+        // $1 is the sidecar path, and after `shift`, "$@" is "sh" "-c" "exit 7".
+        let status = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(STATUS_WRAPPER_SCRIPT)
+            .arg("sh")
+            .arg(&status_path)
+            .arg("sh")
+            .arg("-c")
+            .arg("exit 7")
+            .status()
+            .expect("failed to run wrapper script");
+        assert!(status.success(), "wrapper script itself should exit 0");
+
+        assert_eq!(
+            poll_exit_status(&output_file),
+            DetachedExitStatus::Exited(7)
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_terminate_detached_escalates_to_sigkill() {
+        // Ignores SIGTERM, so terminate_detached must escalate to SIGKILL
+        // once the grace period elapses.
+        // The child must lead its own group before it execs (a parent-side
+        // setpgid after spawn fails once the child has exec'd), so use the
+        // same setsid() hook the real spawn uses.
+        let mut cmd = std::process::Command::new("sh");
+        cmd.arg("-c").arg("trap '' TERM; sleep 5");
+        detach_pre_exec(&mut cmd);
+        let mut child = cmd
+            .spawn()
+            .expect("failed to spawn SIGTERM-ignoring child");
+        let pid = child.id();
+
+        let process = DetachedProcess {
+            pid,
+            pgid: pid,
+            cat_pid: None,
+        };
+        terminate_detached(&process, Duration::from_millis(200))
+            .expect("terminate_detached failed");
+
+        // Give the kernel a moment to deliver the SIGKILL.
+        for _ in 0..20 {
+            if !is_process_alive(pid) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        assert!(!is_process_alive(pid));
+
+        let _ = child.wait();
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_spawn_detached_claude_pipes_input_file_through_cat() {
+        let dir = std::env::temp_dir().join(format!(
+            "jean-detached-test-{}-{}",
+            std::process::id(),
+            "pipeline"
+        ));
+        std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let input_file = dir.join("input.jsonl");
+        let output_file = dir.join("output.jsonl");
+        std::fs::write(&input_file, "hello from the input file").expect("failed to write input");
+        std::fs::write(&output_file, "").expect("failed to create output file");
+
+        // Stand in for Claude with `cat` again, so the pipeline is just
+        // cat(input_file) -> cat(stdin) -> output_file.
+        let process =
+            spawn_detached_claude(Path::new("cat"), &[], &input_file, &output_file, &dir, &[])
+                .expect("failed to spawn detached cat stand-in");
+
+        let mut status = poll_exit_status(&output_file);
+        for _ in 0..50 {
+            if status != DetachedExitStatus::Running {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+            status = poll_exit_status(&output_file);
+        }
+        assert_eq!(status, DetachedExitStatus::Exited(0));
+
+        let contents = std::fs::read_to_string(&output_file).expect("failed to read output file");
+        assert_eq!(contents, "hello from the input file");
+
+        assert!(!is_process_alive(process.pid));
+        assert!(process
+            .cat_pid
+            .is_some_and(|cat_pid| !is_process_alive(cat_pid)));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_spawn_detached_claude_streaming_copies_input_and_records_exit() {
+        let dir = std::env::temp_dir().join(format!(
+            "jean-detached-test-{}-{}",
+            std::process::id(),
+            "streaming"
+        ));
+        std::fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let output_file = dir.join("output.jsonl");
+        std::fs::write(&output_file, "").expect("failed to create output file");
+
+        // Stand in for Claude with `cat`, which just echoes stdin to stdout
+        // (and therefore into output_file).
+        let input = std::io::Cursor::new(b"hello from the streaming writer thread".to_vec());
+        let process =
+            spawn_detached_claude_streaming(Path::new("cat"), &[], input, &output_file, &dir, &[])
+                .expect("failed to spawn streaming cat stand-in");
+
+        // Wait for the wrapper (and the `cat` it runs) to finish and record
+        // its exit status.
+        let mut status = poll_exit_status(&output_file);
+        for _ in 0..50 {
+            if status != DetachedExitStatus::Running {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+            status = poll_exit_status(&output_file);
+        }
+        assert_eq!(status, DetachedExitStatus::Exited(0));
+
+        let contents = std::fs::read_to_string(&output_file).expect("failed to read output file");
+        assert_eq!(contents, "hello from the streaming writer thread");
+
+        assert!(!is_process_alive(process.pid));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -27,3 +27,140 @@ pub fn executable_exists(name: &str) -> bool {
 pub fn executable_exists(name: &str) -> bool {
     which::which(name).is_ok()
 }
+
+/// Quote a single argument for safe embedding in a shell command line.
+///
+/// - Unix: POSIX single-quote escaping for `/bin/sh`-family shells — wrap in
+///   `'...'`, turning any embedded `'` into `'\''`.
+/// - Windows: targets `cmd.exe` specifically (e.g. a `cmd /C "..."` command
+///   line), not `get_default_shell`'s `powershell.exe` — PowerShell has its
+///   own quoting rules that this does not implement. Follows the
+///   `CommandLineToArgvW` backslash-before-quote rules (doubling a run of
+///   backslashes that lands right before the closing quote, escaping
+///   embedded quotes) so the eventual `CreateProcess` argv split is correct,
+///   then additionally escapes cmd.exe's own metacharacters with `^` — cmd's
+///   tokenizer treats `& | < > ^ %` as special even inside double quotes, so
+///   the CommandLineToArgvW quoting alone isn't enough to survive a `cmd /C`
+///   round trip.
+///
+///   The `%` case is the least certain of these: `^%` is the commonly cited
+///   way to stop `cmd.exe` from reading a literal `%` as the start of a
+///   `%VAR%` expansion, but unlike `& | < >` it's still a single character
+///   doing double duty (escape char *and* its own metacharacter).
+///   `test_quote_does_not_leak_a_set_env_var` below checks it against
+///   `cmd.exe` with a same-named variable actually set.
+#[cfg(unix)]
+pub fn quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+#[cfg(windows)]
+pub fn quote(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+    let mut backslashes = 0usize;
+    for c in arg.chars() {
+        match c {
+            '\\' => {
+                backslashes += 1;
+                quoted.push('\\');
+            }
+            '"' => {
+                // Double the pending backslashes, then escape the quote itself.
+                for _ in 0..backslashes {
+                    quoted.push('\\');
+                }
+                quoted.push('\\');
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                backslashes = 0;
+                quoted.push(c);
+            }
+        }
+    }
+    // A run of backslashes right before the closing quote must be doubled,
+    // or CommandLineToArgvW would treat it as escaping that quote.
+    for _ in 0..backslashes {
+        quoted.push('\\');
+    }
+    quoted.push('"');
+
+    let mut cmd_safe = String::with_capacity(quoted.len());
+    for c in quoted.chars() {
+        if matches!(c, '^' | '&' | '|' | '<' | '>' | '%') {
+            cmd_safe.push('^');
+        }
+        cmd_safe.push(c);
+    }
+    cmd_safe
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn test_quote_roundtrips_through_sh() {
+        use std::process::Command;
+
+        for arg in ["hello", "hello world", "it's", "", "a'b'c", "$(rm -rf /)"] {
+            let output = Command::new("sh")
+                .arg("-c")
+                .arg(format!("printf '%s' {}", quote(arg)))
+                .output()
+                .expect("failed to run sh probe");
+            assert_eq!(String::from_utf8_lossy(&output.stdout), arg);
+        }
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_quote_roundtrips_through_cmd() {
+        use std::process::Command;
+
+        for arg in [
+            "hello",
+            "hello world",
+            "a\"b",
+            "a\\b",
+            "a\\\"b",
+            "",
+            "a&b",
+            "a|b",
+            "a^b",
+            "a<b>c",
+            "100%done",
+        ] {
+            let output = Command::new("cmd")
+                .arg("/C")
+                .arg(format!("echo|set /p=\"{}\"", quote(arg)))
+                .output()
+                .expect("failed to run cmd probe");
+            assert_eq!(String::from_utf8_lossy(&output.stdout), arg);
+        }
+    }
+
+    /// Regression test for the `%` case specifically: `100%done` above never
+    /// exercises the actual risk (cmd.exe only tries to expand `%name%` if
+    /// `name` resolves to a real variable), so it would pass even if `quote`
+    /// leaked an env var's value. Set one with a name that collides with the
+    /// arg text and confirm the literal string still comes back, not the
+    /// variable's value.
+    #[test]
+    #[cfg(windows)]
+    fn test_quote_does_not_leak_a_set_env_var() {
+        use std::process::Command;
+
+        std::env::set_var("JEAN_QUOTE_TEST_VAR", "leaked");
+        let arg = "%JEAN_QUOTE_TEST_VAR%done";
+
+        let output = Command::new("cmd")
+            .arg("/C")
+            .arg(format!("echo|set /p=\"{}\"", quote(arg)))
+            .output()
+            .expect("failed to run cmd probe");
+        assert_eq!(String::from_utf8_lossy(&output.stdout), arg);
+    }
+}